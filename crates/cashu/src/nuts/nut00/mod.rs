@@ -364,6 +364,14 @@ impl Proof {
     pub fn y(&self) -> Result<PublicKey, Error> {
         Ok(hash_to_curve(self.secret.as_bytes())?)
     }
+
+    /// NUT-10 [`Kind`](crate::nuts::Kind) of the proof's spending condition
+    ///
+    /// Cheaper than parsing the full [`SpendingConditions`](crate::nuts::SpendingConditions)
+    /// when only the kind is needed, e.g. to route proofs to a verifier.
+    pub fn spending_condition_kind(&self) -> Option<crate::nuts::Kind> {
+        self.secret.kind()
+    }
 }
 
 impl Hash for Proof {
@@ -477,6 +485,25 @@ pub enum CurrencyUnit {
     Custom(String),
 }
 
+impl CurrencyUnit {
+    /// All known [`CurrencyUnit`]s, including [`CurrencyUnit::Auth`]
+    ///
+    /// Does not include [`CurrencyUnit::Custom`]
+    pub fn all() -> Vec<Self> {
+        vec![Self::Sat, Self::Msat, Self::Usd, Self::Eur, Self::Auth]
+    }
+
+    /// Known [`CurrencyUnit`]s that can be minted and melted
+    ///
+    /// Excludes [`CurrencyUnit::Auth`], which is only used for auth keysets
+    pub fn payment_units() -> Vec<Self> {
+        Self::all()
+            .into_iter()
+            .filter(|unit| unit != &Self::Auth)
+            .collect()
+    }
+}
+
 #[cfg(feature = "mint")]
 impl CurrencyUnit {
     /// Derivation index mint will use for unit
@@ -556,6 +583,15 @@ pub enum PaymentMethod {
     Custom(String),
 }
 
+impl PaymentMethod {
+    /// All known [`PaymentMethod`]s
+    ///
+    /// Does not include [`PaymentMethod::Custom`]
+    pub fn all() -> Vec<Self> {
+        vec![Self::Bolt11]
+    }
+}
+
 impl FromStr for PaymentMethod {
     type Err = Error;
     fn from_str(value: &str) -> Result<Self, Self::Err> {
@@ -864,6 +900,32 @@ mod tests {
         assert_eq!(proof.len(), 2);
     }
 
+    #[test]
+    fn test_proof_spending_condition_kind() {
+        let c = PublicKey::from_hex(
+            "02698c4e2b5f9534cd0687d87513c759790cf829aa5739184a3e3735471fbda904",
+        )
+        .unwrap();
+        let keyset_id = Id::from_str("009a1f293253e41e").unwrap();
+
+        let p2pk_secret = Secret::from_str(r#"["P2PK",{"nonce":"859d4935c4907062a6297cf4e663e2835d90d97ecdd510745d32f6816323a41f","data":"0249098aa8b9d2fbec49ff8598feb17b592b986e62319a4fa488a3dc36387157a7","tags":[["sigflag","SIG_INPUTS"]]}]"#).unwrap();
+        let proof = Proof::new(Amount::ONE, keyset_id, p2pk_secret, c);
+        assert_eq!(
+            proof.spending_condition_kind(),
+            Some(crate::nuts::Kind::P2PK)
+        );
+
+        let htlc_secret = Secret::from_str(r#"["HTLC",{"nonce":"d730dd70cd7ec6e687829857de8e70aab2b970712f4dbe288343eca20e63c28c","data":"ec4916dd28fc4c10d78e287ca5d9cc51ee1ae73cbfde08c6b37324cbfaac8bc5"}]"#).unwrap();
+        let proof = Proof::new(Amount::ONE, keyset_id, htlc_secret, c);
+        assert_eq!(
+            proof.spending_condition_kind(),
+            Some(crate::nuts::Kind::HTLC)
+        );
+
+        let proof = Proof::new(Amount::ONE, keyset_id, Secret::generate(), c);
+        assert_eq!(proof.spending_condition_kind(), None);
+    }
+
     #[test]
     #[cfg(feature = "wallet")]
     fn test_blank_blinded_messages() {
@@ -878,4 +940,51 @@ mod tests {
             .unwrap();
         assert_eq!(b.len(), 1);
     }
+
+    #[test]
+    fn test_currency_unit_all() {
+        let expected = [
+            CurrencyUnit::Sat,
+            CurrencyUnit::Msat,
+            CurrencyUnit::Usd,
+            CurrencyUnit::Eur,
+            CurrencyUnit::Auth,
+        ];
+
+        // Exhaustive so a new variant fails to compile until it is listed here
+        for unit in &expected {
+            match unit {
+                CurrencyUnit::Sat
+                | CurrencyUnit::Msat
+                | CurrencyUnit::Usd
+                | CurrencyUnit::Eur
+                | CurrencyUnit::Auth => (),
+                CurrencyUnit::Custom(_) => unreachable!(),
+            }
+        }
+
+        assert_eq!(CurrencyUnit::all(), expected);
+        assert_eq!(
+            CurrencyUnit::payment_units(),
+            expected
+                .into_iter()
+                .filter(|unit| unit != &CurrencyUnit::Auth)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_payment_method_all() {
+        let expected = [PaymentMethod::Bolt11];
+
+        // Exhaustive so a new variant fails to compile until it is listed here
+        for method in &expected {
+            match method {
+                PaymentMethod::Bolt11 => (),
+                PaymentMethod::Custom(_) => unreachable!(),
+            }
+        }
+
+        assert_eq!(PaymentMethod::all(), expected);
+    }
 }
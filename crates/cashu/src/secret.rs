@@ -4,6 +4,7 @@ use std::fmt;
 use std::str::FromStr;
 
 use bitcoin::secp256k1::rand::{self, RngCore};
+use serde::de::IgnoredAny;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -85,6 +86,16 @@ impl Secret {
 
         false
     }
+
+    /// NUT-10 [`Kind`](crate::nuts::Kind) of the secret
+    ///
+    /// Only the kind is parsed, the secret data is skipped. Returns `None` if
+    /// the secret is not a NUT-10 secret.
+    pub fn kind(&self) -> Option<crate::nuts::Kind> {
+        serde_json::from_str::<(crate::nuts::Kind, IgnoredAny)>(&self.0)
+            .ok()
+            .map(|(kind, _)| kind)
+    }
 }
 
 impl FromStr for Secret {
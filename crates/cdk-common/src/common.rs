@@ -24,10 +24,16 @@ pub struct Melted {
     pub amount: Amount,
     /// Fee paid
     pub fee_paid: Amount,
+    /// Fee reserve of the melt quote
+    #[serde(default)]
+    pub fee_reserve: Amount,
 }
 
 impl Melted {
     /// Create new [`Melted`]
+    ///
+    /// Returns [`Error::AmountOverflow`] if the `proofs` or `change_proofs`
+    /// cannot be summed, or if `proofs` do not cover `amount` plus change.
     pub fn from_proofs(
         state: MeltQuoteState,
         preimage: Option<String>,
@@ -35,16 +41,18 @@ impl Melted {
         proofs: Proofs,
         change_proofs: Option<Proofs>,
     ) -> Result<Self, Error> {
-        let proofs_amount = proofs.total_amount()?;
+        let proofs_amount = proofs.total_amount().map_err(|_| Error::AmountOverflow)?;
         let change_amount = match &change_proofs {
-            Some(change_proofs) => change_proofs.total_amount()?,
+            Some(change_proofs) => change_proofs
+                .total_amount()
+                .map_err(|_| Error::AmountOverflow)?,
             None => Amount::ZERO,
         };
 
-        let fee_paid = proofs_amount
-            .checked_sub(amount + change_amount)
-            .ok_or(Error::AmountOverflow)
-            .unwrap();
+        let fee_paid = amount
+            .checked_add(change_amount)
+            .and_then(|spent_amount| proofs_amount.checked_sub(spent_amount))
+            .ok_or(Error::AmountOverflow)?;
 
         Ok(Self {
             state,
@@ -52,29 +60,175 @@ impl Melted {
             change: change_proofs,
             amount,
             fee_paid,
+            fee_reserve: Amount::ZERO,
         })
     }
 
+    /// Create new [`Melted`] recording the fee reserve of the melt quote
+    pub fn from_proofs_with_reserve(
+        state: MeltQuoteState,
+        preimage: Option<String>,
+        amount: Amount,
+        fee_reserve: Amount,
+        proofs: Proofs,
+        change_proofs: Option<Proofs>,
+    ) -> Result<Self, Error> {
+        let mut melted = Self::from_proofs(state, preimage, amount, proofs, change_proofs)?;
+        melted.fee_reserve = fee_reserve;
+
+        Ok(melted)
+    }
+
+    /// Part of the fee reserve that was not needed to pay the fee
+    pub fn overpaid_fee(&self) -> Amount {
+        self.fee_reserve
+            .checked_sub(self.fee_paid)
+            .unwrap_or(Amount::ZERO)
+    }
+
     /// Total amount melted
+    ///
+    /// Assumes validated amounts, e.g. from [`Melted::from_proofs`], and panics
+    /// on overflow. Use [`Melted::checked_total_amount`] otherwise.
     pub fn total_amount(&self) -> Amount {
         self.amount + self.fee_paid
     }
+
+    /// Total amount melted, checking for overflow
+    pub fn checked_total_amount(&self) -> Result<Amount, Error> {
+        self.amount
+            .checked_add(self.fee_paid)
+            .ok_or(Error::AmountOverflow)
+    }
+
+    /// Check if the melt payment has been made
+    pub fn is_fully_paid(&self) -> bool {
+        self.state == MeltQuoteState::Paid
+    }
+
+    /// Split the melt into balanced [`LedgerEntry`]s
+    ///
+    /// The [`LedgerEntryKind::ProofsSpent`] debit equals the sum of the
+    /// payment, change and fee entries.
+    ///
+    /// Returns [`Error::AmountOverflow`] if the amounts do not sum.
+    pub fn to_ledger_entries(&self) -> Result<Vec<LedgerEntry>, Error> {
+        let change_amount = match &self.change {
+            Some(change) => change.total_amount().map_err(|_| Error::AmountOverflow)?,
+            None => Amount::ZERO,
+        };
+
+        let proofs_amount = self
+            .amount
+            .checked_add(self.fee_paid)
+            .and_then(|amount| amount.checked_add(change_amount))
+            .ok_or(Error::AmountOverflow)?;
+
+        let mut entries = vec![
+            LedgerEntry::new(LedgerEntryKind::ProofsSpent, proofs_amount, self.state),
+            LedgerEntry::new(LedgerEntryKind::Payment, self.amount, self.state),
+        ];
+
+        if self.change.is_some() {
+            entries.push(LedgerEntry::new(
+                LedgerEntryKind::Change,
+                change_amount,
+                self.state,
+            ));
+        }
+
+        entries.push(LedgerEntry::new(
+            LedgerEntryKind::Fee,
+            self.fee_paid,
+            self.state,
+        ));
+
+        Ok(entries)
+    }
+
+    /// Classify the [`MeltOutcome`] of the melt
+    pub fn outcome(&self) -> MeltOutcome {
+        match self.state {
+            MeltQuoteState::Paid => MeltOutcome::Succeeded {
+                preimage: self.preimage.clone(),
+            },
+            MeltQuoteState::Pending | MeltQuoteState::Unknown => MeltOutcome::Pending,
+            MeltQuoteState::Unpaid | MeltQuoteState::Failed => MeltOutcome::Failed,
+        }
+    }
+}
+
+/// Kind of [`LedgerEntry`]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LedgerEntryKind {
+    /// Debit of the proofs spent in the melt
+    ProofsSpent,
+    /// Credit of the amount paid
+    Payment,
+    /// Credit of the change returned
+    Change,
+    /// Fee paid
+    Fee,
+}
+
+/// Accounting record of a melt
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    /// Kind of entry
+    pub kind: LedgerEntryKind,
+    /// Amount of entry
+    pub amount: Amount,
+    /// State of the melt quote
+    pub state: MeltQuoteState,
+}
+
+impl LedgerEntry {
+    /// Create new [`LedgerEntry`]
+    pub fn new(kind: LedgerEntryKind, amount: Amount, state: MeltQuoteState) -> Self {
+        Self {
+            kind,
+            amount,
+            state,
+        }
+    }
+}
+
+/// Outcome of a melt
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum MeltOutcome {
+    /// Payment was made
+    Succeeded {
+        /// Preimage of melt payment
+        preimage: Option<String>,
+    },
+    /// Payment is in progress or its state is not known yet
+    Pending,
+    /// Payment was not made
+    Failed,
 }
 
 /// Prooinfo
+///
+/// Persisted by wallet databases, so field names are fixed explicitly.
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ProofInfo {
     /// Proof
+    #[serde(rename = "proof")]
     pub proof: Proof,
     /// y
+    #[serde(rename = "y")]
     pub y: PublicKey,
     /// Mint Url
+    #[serde(rename = "mint_url")]
     pub mint_url: MintUrl,
     /// Proof State
+    #[serde(rename = "state")]
     pub state: State,
     /// Proof Spending Conditions
+    #[serde(rename = "spending_condition", default)]
     pub spending_condition: Option<SpendingConditions>,
     /// Unit
+    #[serde(rename = "unit")]
     pub unit: CurrencyUnit,
 }
 
@@ -100,6 +254,53 @@ impl ProofInfo {
         })
     }
 
+    /// Create a [`ProofInfo`] for each of the [`Proofs`]
+    ///
+    /// Order of `proofs` is preserved. Fails on the first proof that cannot be
+    /// converted with [`Error::InvalidProofAtIndex`], which only happens if
+    /// hashing its secret to the curve finds no valid point.
+    pub fn from_proofs(
+        proofs: Proofs,
+        mint_url: MintUrl,
+        state: State,
+        unit: CurrencyUnit,
+    ) -> Result<Vec<Self>, Error> {
+        proofs
+            .into_iter()
+            .enumerate()
+            .map(|(index, proof)| {
+                Self::new(proof, mint_url.clone(), state, unit.clone())
+                    .map_err(|err| Error::InvalidProofAtIndex(index, Box::new(err)))
+            })
+            .collect()
+    }
+
+    /// Compressed serialization of `y`
+    pub fn y_bytes(&self) -> [u8; 33] {
+        self.y.to_bytes()
+    }
+
+    /// Key used to index the [`ProofInfo`] in a database
+    ///
+    /// This is the compressed `y`, the standard Cashu proof index.
+    pub fn db_key(&self) -> [u8; 33] {
+        self.y_bytes()
+    }
+
+    /// Move the [`ProofInfo`] to `new_state`
+    ///
+    /// Allowed transitions are those of
+    /// [`check_wallet_state_transition`](crate::state::check_wallet_state_transition).
+    /// Prefer this over setting `state` directly.
+    pub fn transition(&mut self, new_state: State) -> Result<(), Error> {
+        crate::state::check_wallet_state_transition(self.state, new_state)
+            .map_err(Error::InvalidStateTransition)?;
+
+        self.state = new_state;
+
+        Ok(())
+    }
+
     /// Check if [`Proof`] matches conditions
     pub fn matches_conditions(
         &self,
@@ -160,6 +361,20 @@ impl PaymentProcessorKey {
     pub fn new(unit: CurrencyUnit, method: PaymentMethod) -> Self {
         Self { unit, method }
     }
+
+    /// Every [`PaymentProcessorKey`] for the known payment units and methods
+    ///
+    /// [`CurrencyUnit::Auth`] is not included as it cannot be minted or melted
+    pub fn all_combinations() -> Vec<Self> {
+        CurrencyUnit::payment_units()
+            .into_iter()
+            .flat_map(|unit| {
+                PaymentMethod::all()
+                    .into_iter()
+                    .map(move |method| Self::new(unit.clone(), method))
+            })
+            .collect()
+    }
 }
 
 /// Secs wuotes are valid
@@ -176,18 +391,43 @@ impl QuoteTTL {
     pub fn new(mint_ttl: u64, melt_ttl: u64) -> QuoteTTL {
         Self { mint_ttl, melt_ttl }
     }
+
+    /// Unix time a mint quote created at `created_at` expires
+    pub fn mint_expiry(&self, created_at: u64) -> u64 {
+        created_at.saturating_add(self.mint_ttl)
+    }
+
+    /// Unix time a melt quote created at `created_at` expires
+    pub fn melt_expiry(&self, created_at: u64) -> u64 {
+        created_at.saturating_add(self.melt_ttl)
+    }
+
+    /// Check if a mint quote created at `created_at` has expired at `now`
+    pub fn is_mint_expired(&self, created_at: u64, now: u64) -> bool {
+        now >= self.mint_expiry(created_at)
+    }
+
+    /// Check if a melt quote created at `created_at` has expired at `now`
+    pub fn is_melt_expired(&self, created_at: u64, now: u64) -> bool {
+        now >= self.melt_expiry(created_at)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
     use std::str::FromStr;
 
     use cashu::SecretKey;
 
-    use super::{Melted, ProofInfo};
+    use super::{LedgerEntryKind, MeltOutcome, Melted, PaymentProcessorKey, ProofInfo, QuoteTTL};
+    use crate::error::Error;
     use crate::mint_url::MintUrl;
-    use crate::nuts::{CurrencyUnit, Id, Proof, PublicKey, SpendingConditions, State};
+    use crate::nuts::{
+        CurrencyUnit, Id, PaymentMethod, Proof, PublicKey, SpendingConditions, State,
+    };
     use crate::secret::Secret;
+    use crate::state::Error as StateError;
     use crate::Amount;
 
     #[test]
@@ -249,6 +489,248 @@ mod tests {
         assert_eq!(melted.total_amount(), Amount::from(32));
     }
 
+    #[test]
+    fn test_melted_ledger_entries() {
+        let keyset_id = Id::from_str("00deadbeef123456").unwrap();
+        let proof = Proof::new(
+            Amount::from(64),
+            keyset_id,
+            Secret::generate(),
+            PublicKey::from_hex(
+                "02deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+            )
+            .unwrap(),
+        );
+        let change_proof = Proof::new(
+            Amount::from(32),
+            keyset_id,
+            Secret::generate(),
+            PublicKey::from_hex(
+                "03deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+            )
+            .unwrap(),
+        );
+        let melted = Melted::from_proofs(
+            super::MeltQuoteState::Paid,
+            Some("preimage".to_string()),
+            Amount::from(31),
+            vec![proof],
+            Some(vec![change_proof]),
+        )
+        .unwrap();
+
+        let entries = melted.to_ledger_entries().unwrap();
+
+        let amount_of = |kind: LedgerEntryKind| {
+            entries
+                .iter()
+                .filter(|entry| entry.kind == kind)
+                .fold(Amount::ZERO, |total, entry| total + entry.amount)
+        };
+
+        assert_eq!(amount_of(LedgerEntryKind::ProofsSpent), Amount::from(64));
+        assert_eq!(amount_of(LedgerEntryKind::Payment), Amount::from(31));
+        assert_eq!(amount_of(LedgerEntryKind::Change), Amount::from(32));
+        assert_eq!(amount_of(LedgerEntryKind::Fee), Amount::from(1));
+        assert_eq!(
+            amount_of(LedgerEntryKind::ProofsSpent),
+            amount_of(LedgerEntryKind::Payment)
+                + amount_of(LedgerEntryKind::Change)
+                + amount_of(LedgerEntryKind::Fee)
+        );
+        assert!(entries
+            .iter()
+            .all(|entry| entry.state == super::MeltQuoteState::Paid));
+    }
+
+    #[test]
+    fn test_melted_amount_overflow() {
+        let keyset_id = Id::from_str("00deadbeef123456").unwrap();
+        let proof = Proof::new(
+            Amount::from(64),
+            keyset_id,
+            Secret::generate(),
+            PublicKey::from_hex(
+                "02deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+            )
+            .unwrap(),
+        );
+        let change_proof = Proof::new(
+            Amount::from(2),
+            keyset_id,
+            Secret::generate(),
+            PublicKey::from_hex(
+                "03deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+            )
+            .unwrap(),
+        );
+        let melted = Melted::from_proofs(
+            super::MeltQuoteState::Paid,
+            Some("preimage".to_string()),
+            Amount::from(u64::MAX),
+            vec![proof],
+            Some(vec![change_proof]),
+        );
+        assert!(matches!(melted, Err(Error::AmountOverflow)));
+    }
+
+    #[test]
+    fn test_melted_change_amount_overflow() {
+        let keyset_id = Id::from_str("00deadbeef123456").unwrap();
+        let proof = Proof::new(
+            Amount::from(64),
+            keyset_id,
+            Secret::generate(),
+            PublicKey::from_hex(
+                "02deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+            )
+            .unwrap(),
+        );
+        let change_proofs = vec![
+            Proof::new(
+                Amount::from(u64::MAX),
+                keyset_id,
+                Secret::generate(),
+                PublicKey::from_hex(
+                    "03deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+                )
+                .unwrap(),
+            ),
+            Proof::new(
+                Amount::ONE,
+                keyset_id,
+                Secret::generate(),
+                PublicKey::from_hex(
+                    "03deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+                )
+                .unwrap(),
+            ),
+        ];
+        let melted = Melted::from_proofs(
+            super::MeltQuoteState::Paid,
+            Some("preimage".to_string()),
+            Amount::from(1),
+            vec![proof],
+            Some(change_proofs.clone()),
+        );
+        assert!(matches!(melted, Err(Error::AmountOverflow)));
+
+        let melted = Melted {
+            change: Some(change_proofs),
+            ..Default::default()
+        };
+        assert!(matches!(
+            melted.to_ledger_entries(),
+            Err(Error::AmountOverflow)
+        ));
+    }
+
+    #[test]
+    fn test_melted_checked_total_amount() {
+        let melted = Melted {
+            amount: Amount::from(31),
+            fee_paid: Amount::from(1),
+            ..Default::default()
+        };
+        assert_eq!(melted.checked_total_amount().unwrap(), Amount::from(32));
+
+        let melted = Melted {
+            amount: Amount::from(u64::MAX - 1),
+            fee_paid: Amount::from(2),
+            ..Default::default()
+        };
+        assert!(matches!(
+            melted.checked_total_amount(),
+            Err(Error::AmountOverflow)
+        ));
+    }
+
+    #[test]
+    fn test_melted_with_reserve() {
+        let keyset_id = Id::from_str("00deadbeef123456").unwrap();
+        let proof = Proof::new(
+            Amount::from(60),
+            keyset_id,
+            Secret::generate(),
+            PublicKey::from_hex(
+                "02deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+            )
+            .unwrap(),
+        );
+        let change_proof = Proof::new(
+            Amount::from(8),
+            keyset_id,
+            Secret::generate(),
+            PublicKey::from_hex(
+                "03deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+            )
+            .unwrap(),
+        );
+        let melted = Melted::from_proofs_with_reserve(
+            super::MeltQuoteState::Paid,
+            Some("preimage".to_string()),
+            Amount::from(50),
+            Amount::from(10),
+            vec![proof],
+            Some(vec![change_proof]),
+        )
+        .unwrap();
+        assert_eq!(melted.fee_reserve, Amount::from(10));
+        assert_eq!(melted.fee_paid, Amount::from(2));
+        assert_eq!(melted.overpaid_fee(), Amount::from(8));
+        assert_eq!(melted.total_amount(), Amount::from(52));
+    }
+
+    #[test]
+    fn test_melted_overpaid_fee_without_reserve() {
+        let melted = Melted {
+            fee_paid: Amount::from(2),
+            ..Default::default()
+        };
+        assert_eq!(melted.overpaid_fee(), Amount::ZERO);
+    }
+
+    #[test]
+    fn test_melted_outcome() {
+        let paid = Melted {
+            state: super::MeltQuoteState::Paid,
+            preimage: Some("preimage".to_string()),
+            ..Default::default()
+        };
+        assert!(paid.is_fully_paid());
+        assert_eq!(
+            paid.outcome(),
+            MeltOutcome::Succeeded {
+                preimage: Some("preimage".to_string())
+            }
+        );
+
+        let paid_without_preimage = Melted {
+            state: super::MeltQuoteState::Paid,
+            preimage: None,
+            ..Default::default()
+        };
+        assert!(paid_without_preimage.is_fully_paid());
+        assert_eq!(
+            paid_without_preimage.outcome(),
+            MeltOutcome::Succeeded { preimage: None }
+        );
+
+        for (state, outcome) in [
+            (super::MeltQuoteState::Pending, MeltOutcome::Pending),
+            (super::MeltQuoteState::Unknown, MeltOutcome::Pending),
+            (super::MeltQuoteState::Unpaid, MeltOutcome::Failed),
+            (super::MeltQuoteState::Failed, MeltOutcome::Failed),
+        ] {
+            let melted = Melted {
+                state,
+                ..Default::default()
+            };
+            assert!(!melted.is_fully_paid());
+            assert_eq!(melted.outcome(), outcome);
+        }
+    }
+
     #[test]
     fn test_matches_conditions() {
         let keyset_id = Id::from_str("00deadbeef123456").unwrap();
@@ -332,6 +814,221 @@ mod tests {
         };
         assert!(!proof_info.matches_conditions(&None, &None, &None, &Some(vec![dummy_condition])));
     }
+
+    #[test]
+    fn test_proof_info_from_proofs() {
+        let keyset_id = Id::from_str("00deadbeef123456").unwrap();
+        let proofs: Vec<Proof> = (0..3)
+            .map(|_| {
+                Proof::new(
+                    Amount::from(8),
+                    keyset_id,
+                    Secret::generate(),
+                    PublicKey::from_hex(
+                        "02deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+                    )
+                    .unwrap(),
+                )
+            })
+            .collect();
+
+        let mint_url = MintUrl::from_str("https://example.com").unwrap();
+        let proof_infos = ProofInfo::from_proofs(
+            proofs.clone(),
+            mint_url.clone(),
+            State::Unspent,
+            CurrencyUnit::Sat,
+        )
+        .unwrap();
+
+        assert_eq!(proof_infos.len(), proofs.len());
+
+        for (proof_info, proof) in proof_infos.iter().zip(proofs) {
+            let expected =
+                ProofInfo::new(proof, mint_url.clone(), State::Unspent, CurrencyUnit::Sat).unwrap();
+            assert_eq!(proof_info.y, expected.y);
+            assert_eq!(proof_info, &expected);
+        }
+    }
+
+    #[test]
+    fn test_proof_info_serde() {
+        let keyset_id = Id::from_str("00deadbeef123456").unwrap();
+        let proof = Proof::new(
+            Amount::from(64),
+            keyset_id,
+            Secret::new("test_secret"),
+            PublicKey::from_hex(
+                "02deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+            )
+            .unwrap(),
+        );
+        let mint_url = MintUrl::from_str("https://example.com").unwrap();
+        let proof_info =
+            ProofInfo::new(proof, mint_url, State::Unspent, CurrencyUnit::Sat).unwrap();
+
+        let expected = format!(
+            r#"{{"proof":{{"amount":64,"id":"00deadbeef123456","secret":"test_secret","C":"02deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef"}},"y":"{}","mint_url":"https://example.com","state":"UNSPENT","spending_condition":null,"unit":"sat"}}"#,
+            proof_info.y.to_hex()
+        );
+
+        assert_eq!(serde_json::to_string(&proof_info).unwrap(), expected);
+        assert_eq!(
+            serde_json::from_str::<ProofInfo>(&expected).unwrap(),
+            proof_info
+        );
+
+        // Payloads without a spending condition are still accepted
+        let without_spending_condition = format!(
+            r#"{{"proof":{{"amount":64,"id":"00deadbeef123456","secret":"test_secret","C":"02deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef"}},"y":"{}","mint_url":"https://example.com","state":"UNSPENT","unit":"sat"}}"#,
+            proof_info.y.to_hex()
+        );
+        let deserialized: ProofInfo = serde_json::from_str(&without_spending_condition).unwrap();
+        assert_eq!(deserialized.spending_condition, None);
+        assert_eq!(deserialized, proof_info);
+    }
+
+    #[test]
+    fn test_proof_info_db_key() {
+        let keyset_id = Id::from_str("00deadbeef123456").unwrap();
+        let secret = Secret::generate();
+        let proof = Proof::new(
+            Amount::from(64),
+            keyset_id,
+            secret.clone(),
+            PublicKey::from_hex(
+                "02deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+            )
+            .unwrap(),
+        );
+        let same_secret_proof = Proof::new(
+            Amount::from(8),
+            keyset_id,
+            secret,
+            PublicKey::from_hex(
+                "03deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+            )
+            .unwrap(),
+        );
+        let mint_url = MintUrl::from_str("https://example.com").unwrap();
+
+        let proof_info =
+            ProofInfo::new(proof, mint_url.clone(), State::Unspent, CurrencyUnit::Sat).unwrap();
+        let same_secret_proof_info =
+            ProofInfo::new(same_secret_proof, mint_url, State::Spent, CurrencyUnit::Sat).unwrap();
+
+        assert_eq!(proof_info.db_key(), same_secret_proof_info.db_key());
+        assert_eq!(proof_info.db_key(), proof_info.y.to_bytes());
+        assert_eq!(proof_info.y_bytes(), proof_info.y.to_bytes());
+    }
+
+    #[test]
+    fn test_proof_info_transition() {
+        let keyset_id = Id::from_str("00deadbeef123456").unwrap();
+        let proof = Proof::new(
+            Amount::from(64),
+            keyset_id,
+            Secret::generate(),
+            PublicKey::from_hex(
+                "02deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef",
+            )
+            .unwrap(),
+        );
+        let mint_url = MintUrl::from_str("https://example.com").unwrap();
+
+        let states = [
+            State::Unspent,
+            State::Pending,
+            State::Reserved,
+            State::PendingSpent,
+            State::Spent,
+        ];
+
+        let allowed = [
+            (State::Unspent, State::Pending),
+            (State::Unspent, State::Reserved),
+            (State::Unspent, State::PendingSpent),
+            (State::Unspent, State::Spent),
+            (State::Pending, State::Unspent),
+            (State::Pending, State::Reserved),
+            (State::Pending, State::Spent),
+            (State::Reserved, State::Unspent),
+            (State::Reserved, State::Reserved),
+            (State::Reserved, State::PendingSpent),
+            (State::Reserved, State::Spent),
+            (State::PendingSpent, State::Unspent),
+            (State::PendingSpent, State::Reserved),
+            (State::PendingSpent, State::Spent),
+        ];
+
+        for from in states {
+            for to in states {
+                let mut proof_info =
+                    ProofInfo::new(proof.clone(), mint_url.clone(), from, CurrencyUnit::Sat)
+                        .unwrap();
+
+                let result = proof_info.transition(to);
+
+                if allowed.contains(&(from, to)) {
+                    assert!(result.is_ok(), "{from} -> {to} should be allowed");
+                    assert_eq!(proof_info.state, to);
+                } else {
+                    assert!(
+                        matches!(
+                            result,
+                            Err(Error::InvalidStateTransition(StateError::InvalidTransition(f, t)))
+                                if f == from && t == to
+                        ),
+                        "{from} -> {to} should be rejected"
+                    );
+                    assert_eq!(proof_info.state, from);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_quote_ttl_expiry() {
+        let quote_ttl = QuoteTTL::new(60, 120);
+
+        assert_eq!(quote_ttl.mint_expiry(1_000), 1_060);
+        assert_eq!(quote_ttl.melt_expiry(1_000), 1_120);
+
+        assert!(!quote_ttl.is_mint_expired(1_000, 1_059));
+        assert!(quote_ttl.is_mint_expired(1_000, 1_060));
+        assert!(!quote_ttl.is_melt_expired(1_000, 1_119));
+        assert!(quote_ttl.is_melt_expired(1_000, 1_120));
+    }
+
+    #[test]
+    fn test_quote_ttl_expiry_saturates() {
+        let quote_ttl = QuoteTTL::new(u64::MAX - 10, u64::MAX);
+
+        assert_eq!(quote_ttl.mint_expiry(1_000), u64::MAX);
+        assert_eq!(quote_ttl.melt_expiry(1_000), u64::MAX);
+        assert!(!quote_ttl.is_mint_expired(1_000, u64::MAX - 1));
+        assert!(!quote_ttl.is_melt_expired(1_000, 1_000_000));
+    }
+
+    #[test]
+    fn test_payment_processor_key_all_combinations() {
+        let keys = PaymentProcessorKey::all_combinations();
+
+        let unique: HashSet<_> = keys.iter().collect();
+        assert_eq!(unique.len(), keys.len());
+
+        for unit in CurrencyUnit::payment_units() {
+            for method in PaymentMethod::all() {
+                assert!(keys.contains(&PaymentProcessorKey::new(unit.clone(), method)));
+            }
+        }
+        assert_eq!(
+            keys.len(),
+            CurrencyUnit::payment_units().len() * PaymentMethod::all().len()
+        );
+
+        assert!(keys.iter().all(|key| key.unit != CurrencyUnit::Auth));
+    }
 }
 
 /// Mint Fee Reserve
@@ -211,6 +211,14 @@ pub enum Error {
     /// Unexpected proof state
     #[error("Unexpected proof state")]
     UnexpectedProofState,
+    /// Wallet proof state transition not allowed
+    ///
+    /// Mint database transitions are reported as [`Error::Database`]
+    #[error(transparent)]
+    InvalidStateTransition(crate::state::Error),
+    /// Proof at index could not be processed
+    #[error("Invalid proof at index `{0}`")]
+    InvalidProofAtIndex(usize, #[source] Box<Error>),
     /// No active keyset
     #[error("No active keyset")]
     NoActiveKeyset,
@@ -37,3 +37,41 @@ pub fn check_state_transition(current_state: State, new_state: State) -> Result<
         Ok(())
     }
 }
+
+#[inline]
+/// Check if the wallet proof state transition is allowed
+///
+/// Transitions follow the wallet's proof state updates:
+/// - `Unspent` -> `Pending` (melt), `Reserved` (prepare send, swap) or
+///   `PendingSpent` (send)
+/// - `Pending` -> `Unspent` (unreserve) or `Reserved` (swap on receive or reclaim)
+/// - `Reserved` -> `Unspent` (cancel send, unreserve), `Reserved` (swap of
+///   reserved proofs) or `PendingSpent` (send)
+/// - `PendingSpent` -> `Unspent` (unreserve) or `Reserved` (swap on reclaim)
+///
+/// Any state but `Spent` may move to `Spent` once the mint reports the proof
+/// spent, and `Spent` is final. `Reserved` -> `Reserved` is the only
+/// self-transition, as swaps reserve proofs a send has already reserved.
+pub fn check_wallet_state_transition(current_state: State, new_state: State) -> Result<(), Error> {
+    let is_valid_transition = match current_state {
+        State::Unspent => matches!(
+            new_state,
+            State::Pending | State::Reserved | State::PendingSpent | State::Spent
+        ),
+        State::Pending => matches!(new_state, State::Unspent | State::Reserved | State::Spent),
+        State::Reserved => matches!(
+            new_state,
+            State::Unspent | State::Reserved | State::PendingSpent | State::Spent
+        ),
+        State::PendingSpent => {
+            matches!(new_state, State::Unspent | State::Reserved | State::Spent)
+        }
+        State::Spent => false,
+    };
+
+    if !is_valid_transition {
+        Err(Error::InvalidTransition(current_state, new_state))
+    } else {
+        Ok(())
+    }
+}
@@ -1136,6 +1136,42 @@ mod tests {
         assert_eq!(states[1], Some(State::Unspent));
     }
 
+    #[tokio::test]
+    async fn test_database_error_conversion() {
+        let tmp_dir = tempdir().unwrap();
+        let path = tmp_dir.path().join("mint.redb");
+
+        let db = MintRedbDatabase::new(&path).unwrap();
+
+        // Opening an already open database is a redb error
+        let Err(err) = MintRedbDatabase::new(&path) else {
+            panic!("Database should already be open");
+        };
+        let err: database::Error = err.into();
+        match err {
+            database::Error::Database(err) => {
+                assert!(matches!(
+                    err.downcast_ref::<Error>(),
+                    Some(Error::Database(db_err))
+                        if matches!(**db_err, redb::DatabaseError::DatabaseAlreadyOpen)
+                ));
+            }
+            err => panic!("Unexpected error: {err}"),
+        }
+
+        // Missing values keep their own variant
+        match db.get_quote_ttl().await {
+            Err(database::Error::Database(err)) => {
+                assert!(matches!(
+                    err.downcast_ref::<Error>(),
+                    Some(Error::UnknownQuoteTTL)
+                ));
+                assert_eq!(err.to_string(), "Unknown quote ttl");
+            }
+            res => panic!("Unexpected result: {res:?}"),
+        }
+    }
+
     async fn provide_db() -> MintRedbDatabase {
         let tmp_dir = tempdir().unwrap();
 
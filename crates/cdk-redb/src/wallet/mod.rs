@@ -557,7 +557,7 @@ impl WalletDatabase for WalletRedbDatabase {
             for proof_info in added.iter() {
                 table
                     .insert(
-                        proof_info.y.to_bytes().as_slice(),
+                        proof_info.db_key().as_slice(),
                         serde_json::to_string(&proof_info)
                             .map_err(Error::from)?
                             .as_str(),
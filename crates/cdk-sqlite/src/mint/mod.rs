@@ -1982,6 +1982,35 @@ mod tests {
         assert_eq!(states[1], Some(State::Unspent));
     }
 
+    #[tokio::test]
+    async fn test_database_error_conversion() {
+        let db = memory::empty().await.unwrap();
+
+        // Missing values keep their own variant
+        match db.get_quote_ttl().await {
+            Err(database::Error::Database(err)) => {
+                assert!(matches!(
+                    err.downcast_ref::<Error>(),
+                    Some(Error::UnknownQuoteTTL)
+                ));
+                assert_eq!(err.to_string(), "Unknown quote TTL");
+            }
+            res => panic!("Unexpected result: {res:?}"),
+        }
+
+        // Using a closed pool is a sqlx error
+        db.pool.close().await;
+        match db.get_quote_ttl().await {
+            Err(database::Error::Database(err)) => {
+                assert!(matches!(
+                    err.downcast_ref::<Error>(),
+                    Some(Error::SQLX(sqlx::Error::PoolClosed))
+                ));
+            }
+            res => panic!("Unexpected result: {res:?}"),
+        }
+    }
+
     async fn provide_db() -> MintSqliteDatabase {
         memory::empty().await.unwrap()
     }
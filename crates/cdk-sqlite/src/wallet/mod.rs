@@ -608,7 +608,7 @@ WHERE id=?
     ;
             "#,
             )
-            .bind(proof.y.to_bytes().to_vec())
+            .bind(proof.db_key().to_vec())
             .bind(proof.mint_url.to_string())
             .bind(proof.state.to_string())
             .bind(
@@ -63,9 +63,9 @@ impl Mint {
 
         let ln = self.get_payment_processor(unit.clone(), PaymentMethod::Bolt11)?;
 
-        let mint_ttl = self.localstore.get_quote_ttl().await?.mint_ttl;
+        let quote_ttl = self.localstore.get_quote_ttl().await?;
 
-        let quote_expiry = unix_time() + mint_ttl;
+        let quote_expiry = quote_ttl.mint_expiry(unix_time());
 
         let settings = ln.get_settings().await?;
         let settings: Bolt11Settings = serde_json::from_value(settings)?;
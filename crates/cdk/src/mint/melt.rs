@@ -150,14 +150,14 @@ impl Mint {
         // or we want to ignore the amount and do an mpp payment
         let msats_to_pay = options.map(|opt| opt.amount_msat());
 
-        let melt_ttl = self.localstore.get_quote_ttl().await?.melt_ttl;
+        let quote_ttl = self.localstore.get_quote_ttl().await?;
 
         let quote = MeltQuote::new(
             request.to_string(),
             unit.clone(),
             payment_quote.amount,
             payment_quote.fee,
-            unix_time() + melt_ttl,
+            quote_ttl.melt_expiry(unix_time()),
             payment_quote.request_lookup_id.clone(),
             msats_to_pay,
         );
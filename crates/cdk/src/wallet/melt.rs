@@ -203,10 +203,11 @@ impl Wallet {
             None => None,
         };
 
-        let melted = Melted::from_proofs(
+        let melted = Melted::from_proofs_with_reserve(
             melt_response.state,
             melt_response.payment_preimage,
             quote_info.amount,
+            quote_info.fee_reserve,
             proofs.clone(),
             change_proofs.clone(),
         )?;